@@ -0,0 +1,210 @@
+//! Criterion benchmark suite for BLS signature verification over Ethereum
+//! attestations. Run with `cargo bench`.
+
+use bls_benchmark::{
+    generate_aggregate_fixture, generate_test_attestations, lazy_aggregate, verify_batch,
+    verify_multiple, AggregateFixture, SignedAttestation, SigningRootMode, DST,
+};
+use blst::BLST_ERROR;
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion, Throughput};
+
+const BATCH_SIZES: &[usize] = &[1, 10, 50, 100];
+const COMMITTEE_SIZE: usize = 1000;
+const LAZY_AGGREGATION_GROUP_SIZE: usize = 8;
+
+/// Sample mainnet-shaped fork data for the SSZ signing-root benchmark; the
+/// actual values don't matter for timing, only that a real `compute_domain`
+/// call (and thus the extra hashing) happens per verification.
+const SAMPLE_FORK_VERSION: [u8; 4] = [0x04, 0x00, 0x00, 0x00];
+const SAMPLE_GENESIS_VALIDATORS_ROOT: [u8; 32] = [0x42; 32];
+
+fn bench_individual_verification(c: &mut Criterion) {
+    let mut group = c.benchmark_group("individual_verification");
+    group.throughput(Throughput::Elements(1));
+
+    for (label, signing_mode) in [
+        ("flat_serialization", SigningRootMode::FlatSerialization),
+        (
+            "ssz_signing_root",
+            SigningRootMode::SszSigningRoot {
+                fork_version: SAMPLE_FORK_VERSION,
+                genesis_validators_root: SAMPLE_GENESIS_VALIDATORS_ROOT,
+            },
+        ),
+    ] {
+        group.bench_function(label, |b| {
+            b.iter_batched(
+                || generate_test_attestations(1, 1, signing_mode),
+                |attestations| {
+                    let attestation = &attestations[0];
+                    // Recompute the signed bytes inside the timed routine so the
+                    // signing-root hashing is part of each measured verification.
+                    let message = signing_mode.signing_message(&attestation.data);
+                    let result = attestation.signature.verify(
+                        true,
+                        &message,
+                        DST,
+                        &[],
+                        &attestation.public_key,
+                        false,
+                    );
+                    assert!(result == BLST_ERROR::BLST_SUCCESS);
+                },
+                BatchSize::SmallInput,
+            );
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_batch_verification(c: &mut Criterion) {
+    let mut group = c.benchmark_group("batch_verification");
+
+    for &batch_size in BATCH_SIZES {
+        group.throughput(Throughput::Elements(batch_size as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(batch_size),
+            &batch_size,
+            |b, &batch_size| {
+                b.iter_batched(
+                    || {
+                        generate_test_attestations(batch_size, 1, SigningRootMode::FlatSerialization)
+                    },
+                    |attestations: Vec<SignedAttestation>| {
+                        let refs: Vec<&SignedAttestation> = attestations.iter().collect();
+                        assert!(verify_batch(&refs, DST));
+                    },
+                    BatchSize::SmallInput,
+                );
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn bench_lazy_aggregation_verification(c: &mut Criterion) {
+    let mut group = c.benchmark_group("lazy_aggregation_verification");
+
+    for &batch_size in BATCH_SIZES {
+        let sample = generate_test_attestations(
+            batch_size,
+            LAZY_AGGREGATION_GROUP_SIZE,
+            SigningRootMode::FlatSerialization,
+        );
+        let sample_result = lazy_aggregate(&sample);
+        let items_out = sample_result.singles.len() + sample_result.aggregated.len();
+        let aggregation_ratio = batch_size as f64 / items_out as f64;
+        println!(
+            "lazy_aggregation_verification/{batch_size}: {batch_size} signatures in, \
+             {items_out} items verified out ({aggregation_ratio:.2}x fewer pairing operations)"
+        );
+
+        group.throughput(Throughput::Elements(batch_size as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(batch_size),
+            &batch_size,
+            |b, &batch_size| {
+                b.iter_batched(
+                    || {
+                        generate_test_attestations(
+                            batch_size,
+                            LAZY_AGGREGATION_GROUP_SIZE,
+                            SigningRootMode::FlatSerialization,
+                        )
+                    },
+                    |attestations: Vec<SignedAttestation>| {
+                        let result = lazy_aggregate(&attestations);
+
+                        for &idx in &result.singles {
+                            let attestation = &attestations[idx];
+                            let verify_result = attestation.signature.verify(
+                                true,
+                                &attestation.message,
+                                DST,
+                                &[],
+                                &attestation.public_key,
+                                false,
+                            );
+                            assert!(verify_result == BLST_ERROR::BLST_SUCCESS);
+                        }
+
+                        if !result.aggregated.is_empty() {
+                            let aggregated_attestations: Vec<(&[u8], &_, &_)> = result
+                                .aggregated
+                                .iter()
+                                .map(|(message, pk, sig)| (message.as_slice(), pk, sig))
+                                .collect();
+                            assert!(verify_multiple(&aggregated_attestations, DST));
+                        }
+                    },
+                    BatchSize::SmallInput,
+                );
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn bench_aggregate_verification(c: &mut Criterion) {
+    let mut group = c.benchmark_group("aggregate_verification");
+    group.throughput(Throughput::Elements(COMMITTEE_SIZE as u64));
+
+    for &same_message in &[true, false] {
+        let label = if same_message {
+            "fast_aggregate_verify"
+        } else {
+            "aggregate_verify"
+        };
+
+        group.bench_function(label, |b| {
+            b.iter_batched(
+                || {
+                    generate_aggregate_fixture(
+                        COMMITTEE_SIZE,
+                        same_message,
+                        SigningRootMode::FlatSerialization,
+                    )
+                },
+                |fixture: AggregateFixture| {
+                    let public_key_refs: Vec<&blst::min_pk::PublicKey> =
+                        fixture.public_keys.iter().collect();
+
+                    let result = if fixture.same_message {
+                        fixture.aggregate_signature.fast_aggregate_verify(
+                            true,
+                            &fixture.messages[0],
+                            DST,
+                            &public_key_refs,
+                        )
+                    } else {
+                        let message_refs: Vec<&[u8]> =
+                            fixture.messages.iter().map(|m| m.as_slice()).collect();
+                        fixture.aggregate_signature.aggregate_verify(
+                            true,
+                            &message_refs,
+                            DST,
+                            &public_key_refs,
+                            false,
+                        )
+                    };
+                    assert!(result == BLST_ERROR::BLST_SUCCESS);
+                },
+                BatchSize::SmallInput,
+            );
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_individual_verification,
+    bench_batch_verification,
+    bench_lazy_aggregation_verification,
+    bench_aggregate_verification
+);
+criterion_main!(benches);
@@ -0,0 +1,496 @@
+//! Fixtures and verification primitives shared by the BLS signature
+//! verification benchmark suite. The actual measurements live in the
+//! Criterion suite under `benches/`; this crate only builds the attestations
+//! and exposes the verification calls they exercise.
+
+use blst::min_pk as bls;
+use blst::{blst_scalar, BLST_ERROR};
+use byteorder::{ByteOrder, LittleEndian};
+use rand::{rngs::ThreadRng, Rng};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+
+/// Domain separation tag for BLS signing/verification, per the Ethereum
+/// consensus spec's BLS12-381 ciphersuite.
+pub const DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+/// `DomainType` for beacon attestations, from the Ethereum consensus spec.
+pub const DOMAIN_BEACON_ATTESTER: [u8; 4] = [0x01, 0x00, 0x00, 0x00];
+
+/// Simplified Ethereum attestation data structure
+#[derive(Debug, Clone)]
+pub struct AttestationData {
+    pub slot: u64,
+    pub index: u64,
+    pub beacon_block_root: [u8; 32],
+    pub source_epoch: u64,
+    pub source_root: [u8; 32],
+    pub target_epoch: u64,
+    pub target_root: [u8; 32],
+}
+
+impl AttestationData {
+    /// Serialize the attestation data to bytes for signing
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(128);
+
+        // Slot (8 bytes)
+        let mut slot_bytes = [0u8; 8];
+        LittleEndian::write_u64(&mut slot_bytes, self.slot);
+        buf.extend_from_slice(&slot_bytes);
+
+        // Index (8 bytes)
+        let mut index_bytes = [0u8; 8];
+        LittleEndian::write_u64(&mut index_bytes, self.index);
+        buf.extend_from_slice(&index_bytes);
+
+        // Beacon block root (32 bytes)
+        buf.extend_from_slice(&self.beacon_block_root);
+
+        // Source epoch (8 bytes)
+        let mut source_epoch_bytes = [0u8; 8];
+        LittleEndian::write_u64(&mut source_epoch_bytes, self.source_epoch);
+        buf.extend_from_slice(&source_epoch_bytes);
+
+        // Source root (32 bytes)
+        buf.extend_from_slice(&self.source_root);
+
+        // Target epoch (8 bytes)
+        let mut target_epoch_bytes = [0u8; 8];
+        LittleEndian::write_u64(&mut target_epoch_bytes, self.target_epoch);
+        buf.extend_from_slice(&target_epoch_bytes);
+
+        // Target root (32 bytes)
+        buf.extend_from_slice(&self.target_root);
+
+        buf
+    }
+
+    /// SSZ hash-tree-root of the container: each of the 7 fixed-size fields
+    /// becomes a 32-byte leaf (`u64` fields are right/zero-padded), and the
+    /// leaves are merkleized with SHA-256 as one flat container. Source and
+    /// target are not nested as separate checkpoint containers, so this root
+    /// is not the one a beacon node computes for its `AttestationData`.
+    pub fn hash_tree_root(&self) -> [u8; 32] {
+        let leaves = [
+            u64_leaf(self.slot),
+            u64_leaf(self.index),
+            self.beacon_block_root,
+            u64_leaf(self.source_epoch),
+            self.source_root,
+            u64_leaf(self.target_epoch),
+            self.target_root,
+        ];
+
+        merkleize(&leaves)
+    }
+}
+
+/// Right/zero-pack a `u64` into a 32-byte SSZ leaf.
+fn u64_leaf(value: u64) -> [u8; 32] {
+    let mut leaf = [0u8; 32];
+    LittleEndian::write_u64(&mut leaf[..8], value);
+    leaf
+}
+
+/// SSZ merkleization: pad the leaves out to the next power of two with
+/// zero leaves, then hash pairs bottom-up with SHA-256 until a single root
+/// remains.
+fn merkleize(leaves: &[[u8; 32]]) -> [u8; 32] {
+    let mut layer: Vec<[u8; 32]> = leaves.to_vec();
+    let padded_len = layer.len().next_power_of_two().max(1);
+    layer.resize(padded_len, [0u8; 32]);
+
+    while layer.len() > 1 {
+        let mut next = Vec::with_capacity(layer.len() / 2);
+        for pair in layer.chunks(2) {
+            let mut hasher = Sha256::new();
+            hasher.update(pair[0]);
+            hasher.update(pair[1]);
+            let digest = hasher.finalize();
+
+            let mut node = [0u8; 32];
+            node.copy_from_slice(&digest);
+            next.push(node);
+        }
+        layer = next;
+    }
+
+    layer[0]
+}
+
+/// `SigningData { object_root, domain }` container from the Ethereum
+/// consensus spec: the actual message a validator signs is the hash-tree-root
+/// of this container, not the `object_root` alone.
+struct SigningData {
+    object_root: [u8; 32],
+    domain: [u8; 32],
+}
+
+impl SigningData {
+    fn signing_root(&self) -> [u8; 32] {
+        merkleize(&[self.object_root, self.domain])
+    }
+}
+
+/// `compute_domain(domain_type, fork_version, genesis_validators_root)` from
+/// the consensus spec: the first 4 bytes identify the domain, the remaining
+/// 28 come from the hash-tree-root of `ForkData { fork_version,
+/// genesis_validators_root }`.
+pub fn compute_domain(
+    domain_type: [u8; 4],
+    fork_version: [u8; 4],
+    genesis_validators_root: [u8; 32],
+) -> [u8; 32] {
+    let mut fork_version_leaf = [0u8; 32];
+    fork_version_leaf[..4].copy_from_slice(&fork_version);
+    let fork_data_root = merkleize(&[fork_version_leaf, genesis_validators_root]);
+
+    let mut domain = [0u8; 32];
+    domain[..4].copy_from_slice(&domain_type);
+    domain[4..].copy_from_slice(&fork_data_root[..28]);
+    domain
+}
+
+/// Selects what gets signed/verified: the benchmark's original ad-hoc flat
+/// serialization, or the spec-accurate SSZ signing root (hash-tree-root of
+/// `AttestationData` combined with the beacon-attester domain). The latter
+/// adds the real hashing cost a beacon node pays per verification.
+#[derive(Debug, Clone, Copy)]
+pub enum SigningRootMode {
+    FlatSerialization,
+    SszSigningRoot {
+        fork_version: [u8; 4],
+        genesis_validators_root: [u8; 32],
+    },
+}
+
+impl SigningRootMode {
+    pub fn signing_message(&self, data: &AttestationData) -> Vec<u8> {
+        match self {
+            SigningRootMode::FlatSerialization => data.serialize(),
+            SigningRootMode::SszSigningRoot {
+                fork_version,
+                genesis_validators_root,
+            } => {
+                let domain =
+                    compute_domain(DOMAIN_BEACON_ATTESTER, *fork_version, *genesis_validators_root);
+                let signing_data = SigningData {
+                    object_root: data.hash_tree_root(),
+                    domain,
+                };
+                signing_data.signing_root().to_vec()
+            }
+        }
+    }
+}
+
+/// Signed attestation with signature and public key
+pub struct SignedAttestation {
+    pub data: AttestationData,
+    /// The exact bytes that were signed (and must be re-supplied on verify),
+    /// per the `SigningRootMode` the attestation was generated with.
+    pub message: Vec<u8>,
+    pub signature: bls::Signature,
+    pub public_key: bls::PublicKey,
+}
+
+/// Generate random bytes of specified length
+fn generate_random_bytes(rng: &mut ThreadRng, length: usize) -> Vec<u8> {
+    let mut bytes = vec![0u8; length];
+    rng.fill(&mut bytes[..]);
+    bytes
+}
+
+/// Generate a random attestation data
+pub fn generate_random_attestation(rng: &mut ThreadRng) -> AttestationData {
+    let mut beacon_block_root = [0u8; 32];
+    let mut source_root = [0u8; 32];
+    let mut target_root = [0u8; 32];
+
+    rng.fill(&mut beacon_block_root);
+    rng.fill(&mut source_root);
+    rng.fill(&mut target_root);
+
+    AttestationData {
+        slot: rng.gen(),
+        index: rng.gen::<u64>() % 65536,
+        beacon_block_root,
+        source_epoch: rng.gen(),
+        source_root,
+        target_epoch: rng.gen(),
+        target_root,
+    }
+}
+
+/// Generate a set of signed attestations for testing.
+///
+/// `shared_message_group_size` controls how many consecutive attestations are
+/// generated against the *same* `AttestationData` (each still signed by its
+/// own keypair), mirroring how a real beacon node sees many validators attest
+/// to the same block in a given slot. A value of `1` gives every attestation
+/// a distinct message, matching the original behavior.
+///
+/// `signing_mode` controls what bytes actually get signed; see
+/// `SigningRootMode`.
+pub fn generate_test_attestations(
+    count: usize,
+    shared_message_group_size: usize,
+    signing_mode: SigningRootMode,
+) -> Vec<SignedAttestation> {
+    let mut rng = rand::thread_rng();
+    let mut attestations = Vec::with_capacity(count);
+    let group_size = shared_message_group_size.max(1);
+
+    let mut current_attestation = generate_random_attestation(&mut rng);
+
+    for i in 0..count {
+        if i > 0 && i % group_size == 0 {
+            current_attestation = generate_random_attestation(&mut rng);
+        }
+        let attestation = current_attestation.clone();
+
+        // Generate a keypair
+        let ikm = generate_random_bytes(&mut rng, 32);
+        let secret_key = bls::SecretKey::key_gen(&ikm, &[]).expect("Failed to generate secret key");
+        let public_key = secret_key.sk_to_pk();
+
+        // Sign the attestation
+        let message = signing_mode.signing_message(&attestation);
+        let signature = secret_key.sign(&message, DST, &[]);
+
+        attestations.push(SignedAttestation {
+            data: attestation,
+            message,
+            signature,
+            public_key,
+        });
+    }
+
+    attestations
+}
+
+/// Verify a batch of (message, public key, signature) triples in a single
+/// multi-Miller-loop + final-exponentiation, via blst's
+/// `verify_multiple_aggregate_signatures`.
+///
+/// Each triple is weighted by an independent random non-zero 64-bit scalar
+/// before being folded into the shared pairing context. Without these
+/// coefficients an attacker could construct a set of individually-invalid
+/// signatures whose aggregate still happens to verify; the random linear
+/// combination makes that negligibly unlikely.
+pub fn verify_multiple(items: &[(&[u8], &bls::PublicKey, &bls::Signature)], dst: &[u8]) -> bool {
+    let mut rng = rand::thread_rng();
+
+    let messages: Vec<&[u8]> = items.iter().map(|(message, _, _)| *message).collect();
+    let public_keys: Vec<&bls::PublicKey> = items.iter().map(|(_, pk, _)| *pk).collect();
+    let signatures: Vec<&bls::Signature> = items.iter().map(|(_, _, sig)| *sig).collect();
+    let random_scalars: Vec<blst_scalar> = items
+        .iter()
+        .map(|_| {
+            let mut b = [0u8; 32];
+            b[..8].copy_from_slice(&rng.gen_range(1..=u64::MAX).to_le_bytes());
+            blst_scalar { b }
+        })
+        .collect();
+
+    let result = bls::Signature::verify_multiple_aggregate_signatures(
+        &messages,
+        dst,
+        &public_keys,
+        false, // pks_validate: keys were already generated by us
+        &signatures,
+        false, // sigs_groupcheck
+        &random_scalars,
+        64,
+    );
+
+    result == BLST_ERROR::BLST_SUCCESS
+}
+
+/// Verify a batch of attestations in a single multi-Miller-loop +
+/// final-exponentiation; see `verify_multiple` for the underlying technique.
+pub fn verify_batch(attestations: &[&SignedAttestation], dst: &[u8]) -> bool {
+    let items: Vec<(&[u8], &bls::PublicKey, &bls::Signature)> = attestations
+        .iter()
+        .map(|a| (a.message.as_slice(), &a.public_key, &a.signature))
+        .collect();
+
+    verify_multiple(&items, dst)
+}
+
+/// Result of the lazy-aggregation pre-pass over a slice of attestations.
+pub struct LazyAggregationResult {
+    /// Indices of attestations that were alone in their message bucket;
+    /// verified directly to avoid the random-scalar overhead of the batch
+    /// verifier.
+    pub singles: Vec<usize>,
+    /// One (message, aggregated public key, aggregated signature) triple per
+    /// bucket with two or more attestations sharing a message.
+    pub aggregated: Vec<(Vec<u8>, bls::PublicKey, bls::Signature)>,
+}
+
+/// Bucket attestations by their signed message and collapse each bucket
+/// with more than one member into a single aggregated (public key,
+/// signature) pair via point addition in G1/G2, ready to be checked by the
+/// random-coefficient batch verifier.
+pub fn lazy_aggregate(attestations: &[SignedAttestation]) -> LazyAggregationResult {
+    let mut buckets: HashMap<Vec<u8>, Vec<usize>> = HashMap::new();
+    for (i, attestation) in attestations.iter().enumerate() {
+        buckets.entry(attestation.message.clone()).or_default().push(i);
+    }
+
+    let mut singles = Vec::new();
+    let mut aggregated = Vec::new();
+
+    for (message, indices) in buckets {
+        if indices.len() == 1 {
+            singles.push(indices[0]);
+            continue;
+        }
+
+        let mut agg_pk =
+            bls::AggregatePublicKey::from_public_key(&attestations[indices[0]].public_key);
+        let mut agg_sig =
+            bls::AggregateSignature::from_signature(&attestations[indices[0]].signature);
+        for &idx in &indices[1..] {
+            agg_pk
+                .add_public_key(&attestations[idx].public_key, false)
+                .expect("Failed to add public key to aggregate");
+            agg_sig
+                .add_signature(&attestations[idx].signature, false)
+                .expect("Failed to add signature to aggregate");
+        }
+
+        aggregated.push((message, agg_pk.to_public_key(), agg_sig.to_signature()));
+    }
+
+    LazyAggregationResult { singles, aggregated }
+}
+
+/// A committee of keypairs whose individual signatures have been combined
+/// into a single `AggregateSignature`, mirroring an aggregated attestation
+/// on a real beacon block. When `same_message` is true every member signs
+/// the same `AttestationData` (the `fast_aggregate_verify` case); otherwise
+/// each member signs its own, distinct message (the `aggregate_verify` case).
+pub struct AggregateFixture {
+    pub same_message: bool,
+    pub messages: Vec<Vec<u8>>,
+    pub public_keys: Vec<bls::PublicKey>,
+    pub aggregate_signature: bls::Signature,
+}
+
+/// Generate a committee of `committee_size` keypairs and combine their
+/// signatures into a single `AggregateSignature`.
+pub fn generate_aggregate_fixture(
+    committee_size: usize,
+    same_message: bool,
+    signing_mode: SigningRootMode,
+) -> AggregateFixture {
+    assert!(committee_size > 0, "committee_size must be positive");
+
+    let mut rng = rand::thread_rng();
+    let shared_attestation = generate_random_attestation(&mut rng);
+
+    let mut messages = Vec::with_capacity(committee_size);
+    let mut public_keys = Vec::with_capacity(committee_size);
+    let mut agg_sig: Option<bls::AggregateSignature> = None;
+
+    for _ in 0..committee_size {
+        let attestation = if same_message {
+            shared_attestation.clone()
+        } else {
+            generate_random_attestation(&mut rng)
+        };
+
+        let ikm = generate_random_bytes(&mut rng, 32);
+        let secret_key = bls::SecretKey::key_gen(&ikm, &[]).expect("Failed to generate secret key");
+        let public_key = secret_key.sk_to_pk();
+
+        let message = signing_mode.signing_message(&attestation);
+        let signature = secret_key.sign(&message, DST, &[]);
+
+        match &mut agg_sig {
+            Some(agg) => agg
+                .add_signature(&signature, false)
+                .expect("Failed to add signature to aggregate"),
+            None => agg_sig = Some(bls::AggregateSignature::from_signature(&signature)),
+        }
+
+        messages.push(message);
+        public_keys.push(public_key);
+    }
+
+    AggregateFixture {
+        same_message,
+        messages,
+        public_keys,
+        aggregate_signature: agg_sig.expect("committee_size must be positive").to_signature(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_attestation_data() -> AttestationData {
+        AttestationData {
+            slot: 1,
+            index: 2,
+            beacon_block_root: [0x11; 32],
+            source_epoch: 3,
+            source_root: [0x22; 32],
+            target_epoch: 4,
+            target_root: [0x33; 32],
+        }
+    }
+
+    // Expected roots below were computed independently in Python against the
+    // same field layout (little-endian `u64` leaves, right-zero-padded,
+    // SHA-256 pairwise merkleization) to catch a regression here that a
+    // sign-then-verify round trip in the benchmark itself would not.
+
+    #[test]
+    fn hash_tree_root_matches_hand_computed_vector() {
+        let root = sample_attestation_data().hash_tree_root();
+        assert_eq!(
+            root,
+            [
+                0x8b, 0x5d, 0xf2, 0x5b, 0xaf, 0x47, 0x54, 0x18, 0x53, 0x78, 0x92, 0x6c, 0xb1,
+                0xe6, 0x2b, 0x91, 0x07, 0x0f, 0x71, 0x48, 0x4f, 0x1e, 0xb5, 0x81, 0x05, 0xa4,
+                0x92, 0x52, 0x10, 0x95, 0xd5, 0x35,
+            ]
+        );
+    }
+
+    #[test]
+    fn compute_domain_matches_hand_computed_vector() {
+        let domain = compute_domain([0x01, 0x00, 0x00, 0x00], [0x04, 0x00, 0x00, 0x00], [0x42; 32]);
+        assert_eq!(
+            domain,
+            [
+                0x01, 0x00, 0x00, 0x00, 0x96, 0xe6, 0x56, 0x07, 0xd8, 0x4f, 0xbf, 0xdf, 0x3e,
+                0xa6, 0xa8, 0xbb, 0x79, 0x2b, 0xa4, 0x3f, 0xdc, 0x81, 0x3d, 0x89, 0x9c, 0x29,
+                0x06, 0xd7, 0xfb, 0xa5, 0x07, 0xde,
+            ]
+        );
+    }
+
+    #[test]
+    fn signing_root_matches_hand_computed_vector() {
+        let data = sample_attestation_data();
+        let domain = compute_domain([0x01, 0x00, 0x00, 0x00], [0x04, 0x00, 0x00, 0x00], [0x42; 32]);
+        let signing_data = SigningData {
+            object_root: data.hash_tree_root(),
+            domain,
+        };
+        assert_eq!(
+            signing_data.signing_root(),
+            [
+                0xdf, 0xac, 0xd5, 0xab, 0xe3, 0x77, 0xd2, 0x65, 0xbc, 0x35, 0x73, 0x26, 0xd2,
+                0xa5, 0x74, 0x53, 0x46, 0x9d, 0x5d, 0x70, 0xf3, 0x75, 0x4d, 0x03, 0x1c, 0x02,
+                0x6c, 0x94, 0x86, 0xf0, 0x7d, 0x11,
+            ]
+        );
+    }
+}